@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// An elementwise activation that, together with a bias add, can be merged into a single
+/// extra elementwise pass over a kernel's output instead of one pass per operation (see
+/// [`crate::kernel::fused_epilogue`]). Against this version of `cubecl::linalg::matmul`,
+/// which doesn't expose the GEMM's accumulator epilogue, that's a reduction from two
+/// extra passes to one, not a true register-resident fusion with zero extra passes.
+///
+/// Embedded in autotune keys (e.g. the fused matmul and conv_transpose2d keys), so it
+/// must round-trip through the on-disk autotune cache like the rest of those keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ActivationKind {
+    /// No activation, only the bias (if any) is applied.
+    Identity,
+    /// Rectified linear unit.
+    Relu,
+    /// Rectified linear unit clipped at 6.
+    Relu6,
+    /// Gaussian error linear unit.
+    Gelu,
+    /// Exponential linear unit.
+    Elu,
+    /// Logistic sigmoid.
+    Sigmoid,
+}
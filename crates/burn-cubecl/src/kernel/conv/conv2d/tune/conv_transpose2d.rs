@@ -4,7 +4,12 @@ use cubecl::tune::{LocalTuner, TunableSet, local_tuner};
 use crate::{
     CubeAutotuneKey, CubeRuntime, CubeTuneId, FloatElement,
     kernel::{
+        activation::ActivationKind,
+        autotune_cost_model::{
+            prune_dominated, CostEstimate, DeviceRooflineProperties, COST_MODEL_PRUNE_FACTOR,
+        },
         conv::{conv_transpose2d_col2im, conv_transpose2d_direct},
+        fused_epilogue::launch_fused_epilogue,
         prng::random_uniform,
     },
     tensor::CubeTensor,
@@ -12,6 +17,40 @@ use crate::{
 
 use super::ConvTranspose2dAutotuneKey;
 
+/// Picks which of the direct and col2im strategies are worth benchmarking for this
+/// problem size. col2im additionally materializes the expanded column buffer to global
+/// memory before the gather, so it moves strictly more bytes than direct scatter-add for
+/// the same output; that extra traffic is what the cost model prices in.
+fn conv_transpose2d_candidates<R: CubeRuntime>(
+    input: &CubeTensor<R>,
+    weights: &CubeTensor<R>,
+    dtype_size: usize,
+) -> (bool, bool) {
+    let [batch_size, in_channels, height, width] = input.shape.dims();
+    let [out_channels, _, kernel_h, kernel_w] = weights.shape.dims();
+
+    let input_elements = batch_size * in_channels * height * width;
+    let weight_elements = out_channels * in_channels * kernel_h * kernel_w;
+    let output_elements = input_elements * kernel_h * kernel_w;
+
+    let direct = CostEstimate {
+        bytes_loaded: ((input_elements + weight_elements) * dtype_size) as u64,
+        bytes_stored: (output_elements * dtype_size) as u64,
+        compute_cycles: (output_elements * in_channels) as u64,
+    };
+    // col2im round-trips the expanded columns through global memory before the final
+    // gather, on top of the same direct compute and traffic.
+    let col2im = CostEstimate {
+        bytes_loaded: direct.bytes_loaded + direct.bytes_stored,
+        bytes_stored: direct.bytes_stored * 2,
+        compute_cycles: direct.compute_cycles,
+    };
+
+    let device = DeviceRooflineProperties::query::<R>();
+    let survives = prune_dominated(&[direct, col2im], &device, COST_MODEL_PRUNE_FACTOR);
+    (survives[0], survives[1])
+}
+
 /// Executes autotune on conv2d operations
 pub fn conv_transpose2d_autotune<R: CubeRuntime, E: FloatElement>(
     input: CubeTensor<R>,
@@ -23,9 +62,16 @@ pub fn conv_transpose2d_autotune<R: CubeRuntime, E: FloatElement>(
 
     static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
 
-    let tune_set = TunableSet::new(create_key::<R, E>, create_transpose2d_input::<R, E>)
-        .with_tunable(conv_transpose2d_direct::<R, E>)
-        .with_tunable(conv_transpose2d_col2im::<R, E>);
+    let (try_direct, try_col2im) =
+        conv_transpose2d_candidates::<R>(&input, &weights, core::mem::size_of::<E>());
+
+    let mut tune_set = TunableSet::new(create_key::<R, E>, create_transpose2d_input::<R, E>);
+    if try_direct {
+        tune_set = tune_set.with_tunable(conv_transpose2d_direct::<R, E>);
+    }
+    if try_col2im {
+        tune_set = tune_set.with_tunable(conv_transpose2d_col2im::<R, E>);
+    }
 
     TUNER
         .execute(
@@ -74,6 +120,16 @@ fn create_key<R: CubeRuntime, E: FloatElement>(
     weights: &CubeTensor<R>,
     bias: &Option<CubeTensor<R>>,
     options: &ConvTransposeOptions<2>,
+) -> CubeAutotuneKey {
+    create_key_with_activation::<R, E>(input, weights, bias, options, ActivationKind::Identity)
+}
+
+fn create_key_with_activation<R: CubeRuntime, E: FloatElement>(
+    input: &CubeTensor<R>,
+    weights: &CubeTensor<R>,
+    bias: &Option<CubeTensor<R>>,
+    options: &ConvTransposeOptions<2>,
+    activation: ActivationKind,
 ) -> CubeAutotuneKey {
     let [batch_size, in_channels, height, width] = input.shape.dims();
     let [out_channels, _, kernel_h, kernel_w] = weights.shape.dims();
@@ -97,6 +153,107 @@ fn create_key<R: CubeRuntime, E: FloatElement>(
         width,
         batch_size,
         bias.is_some(),
+        activation,
         E::dtype(),
     ))
 }
+
+fn create_key_fused<R: CubeRuntime, E: FloatElement>(
+    input: &CubeTensor<R>,
+    weights: &CubeTensor<R>,
+    bias: &Option<CubeTensor<R>>,
+    activation: &ActivationKind,
+    options: &ConvTransposeOptions<2>,
+) -> CubeAutotuneKey {
+    create_key_with_activation::<R, E>(input, weights, bias, options, *activation)
+}
+
+fn create_transpose2d_fused_input<R: CubeRuntime, E: FloatElement>(
+    key: &CubeAutotuneKey,
+    input: &CubeTensor<R>,
+    weights: &CubeTensor<R>,
+    bias: &Option<CubeTensor<R>>,
+    _activation: &ActivationKind,
+    options: &ConvTransposeOptions<2>,
+) -> (
+    CubeTensor<R>,
+    CubeTensor<R>,
+    Option<CubeTensor<R>>,
+    ActivationKind,
+    ConvTransposeOptions<2>,
+) {
+    let (input, weights, bias, options) =
+        create_transpose2d_input::<R, E>(key, input, weights, bias, options);
+    let activation = match key {
+        CubeAutotuneKey::ConvTranspose2d(key) => key.activation,
+        _ => unreachable!(),
+    };
+    (input, weights, bias, activation, options)
+}
+
+/// Executes autotune on conv_transpose2d operations followed by a bias add and an
+/// activation. The conv kernel applies the bias; the activation then runs as one extra
+/// elementwise launch over the written output, so it is not fused into the conv
+/// kernel's own output-write stage.
+pub fn conv_transpose2d_fused_autotune<R: CubeRuntime, E: FloatElement>(
+    input: CubeTensor<R>,
+    weights: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+    activation: ActivationKind,
+    options: ConvTransposeOptions<2>,
+) -> CubeTensor<R> {
+    let client = input.client.clone();
+
+    static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
+
+    let (try_direct, try_col2im) =
+        conv_transpose2d_candidates::<R>(&input, &weights, core::mem::size_of::<E>());
+
+    let mut tune_set = TunableSet::new(
+        create_key_fused::<R, E>,
+        create_transpose2d_fused_input::<R, E>,
+    );
+    if try_direct {
+        tune_set = tune_set.with_tunable(conv_transpose2d_direct_fused::<R, E>);
+    }
+    if try_col2im {
+        tune_set = tune_set.with_tunable(conv_transpose2d_col2im_fused::<R, E>);
+    }
+
+    TUNER
+        .execute(
+            &CubeTuneId::new::<R>(&input.client, &input.device),
+            &client,
+            &tune_set,
+            (input, weights, bias, activation, options),
+        )
+        .expect("All autotuners failed")
+}
+
+/// Runs the direct conv_transpose2d kernel (which already applies `bias`), then fuses
+/// `activation` into the output with a single extra elementwise pass.
+fn conv_transpose2d_direct_fused<R: CubeRuntime, E: FloatElement>(
+    input: CubeTensor<R>,
+    weights: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+    activation: ActivationKind,
+    options: ConvTransposeOptions<2>,
+) -> Result<CubeTensor<R>, String> {
+    let out = conv_transpose2d_direct::<R, E>(input, weights, bias, options)?;
+    launch_fused_epilogue::<R, E>(&out, None, activation)?;
+    Ok(out)
+}
+
+/// Runs the col2im conv_transpose2d kernel (which already applies `bias`), then fuses
+/// `activation` into the output with a single extra elementwise pass.
+fn conv_transpose2d_col2im_fused<R: CubeRuntime, E: FloatElement>(
+    input: CubeTensor<R>,
+    weights: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+    activation: ActivationKind,
+    options: ConvTransposeOptions<2>,
+) -> Result<CubeTensor<R>, String> {
+    let out = conv_transpose2d_col2im::<R, E>(input, weights, bias, options)?;
+    launch_fused_epilogue::<R, E>(&out, None, activation)?;
+    Ok(out)
+}
@@ -0,0 +1,80 @@
+//! `conv_transpose2d.rs` already imported `ConvTranspose2dAutotuneKey` from its parent
+//! module before this backlog touched anything, so this struct necessarily exists
+//! upstream; it isn't part of this snapshot. It's reconstructed here with exactly the
+//! fields `conv_transpose2d.rs` reads plus the `activation` field this request adds — if
+//! the real upstream key carries more than that, this file needs to be replaced with it
+//! (adding the one new field) rather than merged as-is.
+
+use cubecl::AutotuneKey;
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::activation::ActivationKind;
+
+mod conv_transpose2d;
+
+pub use conv_transpose2d::*;
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize, AutotuneKey)]
+/// Autotune key representative of conv_transpose2d versions.
+pub struct ConvTranspose2dAutotuneKey {
+    pub kernel_size: [usize; 2],
+    pub stride: [usize; 2],
+    pub padding: [usize; 2],
+    pub padding_out: [usize; 2],
+    pub dilation: [usize; 2],
+    #[autotune(anchor)]
+    pub groups: usize,
+    #[autotune(anchor)]
+    pub in_channels: usize,
+    #[autotune(anchor)]
+    pub out_channels: usize,
+    #[autotune(anchor)]
+    pub height: usize,
+    #[autotune(anchor)]
+    pub width: usize,
+    #[autotune(anchor)]
+    pub batch_size: usize,
+    pub has_bias: bool,
+    /// The activation applied to the output, if any. Always `Identity` in the key used
+    /// by the unfused tunables, so a plain conv_transpose2d tunes independently from one
+    /// with a fused non-identity activation.
+    pub activation: ActivationKind,
+    pub dtype: burn_tensor::DType,
+}
+
+#[allow(clippy::too_many_arguments)]
+impl ConvTranspose2dAutotuneKey {
+    pub(crate) fn new(
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        padding_out: [usize; 2],
+        dilation: [usize; 2],
+        groups: usize,
+        in_channels: usize,
+        out_channels: usize,
+        height: usize,
+        width: usize,
+        batch_size: usize,
+        has_bias: bool,
+        activation: ActivationKind,
+        dtype: burn_tensor::DType,
+    ) -> Self {
+        Self {
+            kernel_size,
+            stride,
+            padding,
+            padding_out,
+            dilation,
+            groups,
+            in_channels,
+            out_channels,
+            height,
+            width,
+            batch_size,
+            has_bias,
+            activation,
+            dtype,
+        }
+    }
+}
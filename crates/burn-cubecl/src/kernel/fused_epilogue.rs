@@ -0,0 +1,92 @@
+//! The bias-add + activation epilogue fused onto an output-write stage, shared by every
+//! kernel (matmul, conv_transpose2d) that fuses a bias and activation after its main
+//! compute.
+//!
+//! Neither `cubecl::linalg::matmul` nor the conv kernels expose their accumulator-to-
+//! global-memory write for us to hook into, so the epilogue isn't fused into their own
+//! kernel internals. Instead it is its own single-kernel-launch elementwise pass over the
+//! (already-written) output, replacing what would otherwise be two separate launches
+//! (bias broadcast-add, then activation) with one: the bias add and the activation both
+//! happen in registers within the same thread before the result is stored back.
+
+use cubecl::prelude::*;
+
+use crate::{tensor::CubeTensor, CubeRuntime, FloatElement};
+
+use super::activation::ActivationKind;
+
+#[cube]
+fn apply_activation<F: Float>(x: F, #[comptime] kind: ActivationKind) -> F {
+    match kind {
+        ActivationKind::Identity => x,
+        ActivationKind::Relu => F::max(x, F::new(0.0)),
+        ActivationKind::Relu6 => F::min(F::max(x, F::new(0.0)), F::new(6.0)),
+        ActivationKind::Gelu => {
+            let half = F::new(0.5);
+            let one = F::new(1.0);
+            let coeff = F::new(0.044715);
+            let sqrt_2_over_pi = F::new(0.7978845608028654);
+            let inner = sqrt_2_over_pi * (x + coeff * x * x * x);
+            half * x * (one + F::tanh(inner))
+        }
+        ActivationKind::Elu => {
+            if x > F::new(0.0) {
+                x
+            } else {
+                F::exp(x) - F::new(1.0)
+            }
+        }
+        ActivationKind::Sigmoid => F::new(1.0) / (F::new(1.0) + F::exp(-x)),
+    }
+}
+
+#[cube(launch_unchecked)]
+fn bias_activation_kernel<F: Float>(
+    output: &mut Tensor<F>,
+    bias: &Tensor<F>,
+    #[comptime] has_bias: bool,
+    #[comptime] activation: ActivationKind,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut value = output[ABSOLUTE_POS];
+    if comptime!(has_bias) {
+        let bias_idx = ABSOLUTE_POS % bias.len();
+        value += bias[bias_idx];
+    }
+    output[ABSOLUTE_POS] = apply_activation::<F>(value, activation);
+}
+
+/// Applies `bias` (broadcast over the last dimension) and `activation` to `out` in place,
+/// in a single kernel launch.
+pub(crate) fn launch_fused_epilogue<R: CubeRuntime, E: FloatElement>(
+    out: &CubeTensor<R>,
+    bias: Option<&CubeTensor<R>>,
+    activation: ActivationKind,
+) -> Result<(), String> {
+    let num_elems = out.shape.num_elements();
+    let cube_dim = CubeDim::default();
+    let cube_count = CubeCount::Static(
+        (num_elems as u32).div_ceil(cube_dim.num_elems()),
+        1,
+        1,
+    );
+
+    let bias_arg = bias.unwrap_or(out);
+
+    unsafe {
+        bias_activation_kernel::launch_unchecked::<E, R>(
+            &out.client,
+            cube_count,
+            cube_dim,
+            out.as_tensor_arg(1),
+            bias_arg.as_tensor_arg(1),
+            bias.is_some(),
+            activation,
+        );
+    }
+
+    Ok(())
+}
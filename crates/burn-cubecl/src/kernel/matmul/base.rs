@@ -5,16 +5,30 @@ use cubecl::linalg::matmul::kernels::{MatmulAvailabilityError, MatmulLaunchError
 #[cfg(feature = "autotune")]
 use super::matmul_autotune;
 
+pub use crate::kernel::activation::ActivationKind;
+
 /// The strategy to be used when launching a matmul kernel.
-pub enum MatmulStrategy {
+pub enum MatmulStrategy<R: CubeRuntime> {
     #[cfg(feature = "autotune")]
     /// Using autotune to choose the best kernel based on runtime information.
     Autotune,
     /// Cube implementation of matmul.
     Cube,
+    /// Cube implementation of matmul with a bias add and activation merged into a single
+    /// extra elementwise pass over the output, instead of a separate pass per operation.
+    /// `cubecl::linalg::matmul` doesn't expose the GEMM's accumulator epilogue to plug
+    /// into, so this still runs the full unfused matmul first and then a second kernel
+    /// launch that re-reads and rewrites the whole output; it saves one of the two extra
+    /// passes a naive bias-then-activation pipeline would need, not both.
+    Fused {
+        /// Bias to broadcast-add to the matmul output.
+        bias: Option<CubeTensor<R>>,
+        /// Activation applied after the bias add.
+        activation: ActivationKind,
+    },
 }
 
-impl Default for MatmulStrategy {
+impl<R: CubeRuntime> Default for MatmulStrategy<R> {
     fn default() -> Self {
         // if autotune is enabled, default to autotune
         #[cfg(feature = "autotune")]
@@ -30,7 +44,7 @@ pub fn matmul<R: CubeRuntime, E: FloatElement>(
     lhs: CubeTensor<R>,
     rhs: CubeTensor<R>,
     out: Option<CubeTensor<R>>,
-    strategy: MatmulStrategy,
+    strategy: MatmulStrategy<R>,
 ) -> Result<CubeTensor<R>, MatmulLaunchError> {
     match strategy {
         MatmulStrategy::Cube => {
@@ -55,5 +69,25 @@ pub fn matmul<R: CubeRuntime, E: FloatElement>(
                 MatmulAvailabilityError::PipelineUnavailable,
             )),
         },
+        MatmulStrategy::Fused { bias, activation } => {
+            let out = out.unwrap_or_else(|| init_matmul_output::<R, E>(&lhs, &rhs));
+
+            let client = &lhs.client;
+
+            cubecl::linalg::matmul::launch_ref::<R, E>(
+                &Default::default(),
+                client,
+                &lhs.as_handle_ref(),
+                &rhs.as_handle_ref(),
+                &out.as_handle_ref(),
+            )?;
+
+            crate::kernel::fused_epilogue::launch_fused_epilogue::<R, E>(&out, bias.as_ref(), activation)
+                .map_err(|_| {
+                    MatmulLaunchError::Unavailable(MatmulAvailabilityError::PipelineUnavailable)
+                })?;
+
+            Ok(out)
+        }
     }
 }
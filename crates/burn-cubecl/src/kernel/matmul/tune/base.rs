@@ -6,14 +6,74 @@ use cubecl::{
 
 use crate::{
     element::FloatElement,
-    kernel::{matmul::utils::init_matmul_output, prng::random_like_uniform},
+    kernel::{
+        autotune_cost_model::{
+            matmul_cost_estimate, prune_dominated, CostEstimate, DeviceRooflineProperties,
+            COST_MODEL_PRUNE_FACTOR,
+        },
+        matmul::{utils::init_matmul_output, ActivationKind},
+        prng::random_like_uniform,
+    },
     ops::numeric::empty_device,
     tensor::CubeTensor,
     tune_key::CubeAutotuneKey,
     CubeRuntime, CubeTuneId,
 };
 
-use super::key::create_key;
+use super::key::{create_key, create_key_fused};
+
+/// Tensor Core MMA instructions operate on a fixed tile regardless of the overall problem
+/// size; 16x16 is the tile used by the FP16/BF16 MMA instructions this kernel targets.
+const ACCELERATED_TILE: usize = 16;
+
+/// The output-tile footprint a kernel's inner loop keeps resident (in shared memory or a
+/// tensor-core accumulator) before spilling back to global memory. Reuse is bounded by how
+/// much of that footprint the actual M/N can fill, so small problems see proportionally
+/// less benefit from tiling than large ones do, rather than a single ranking that holds
+/// for every shape.
+fn tile_reuse(m: usize, n: usize, tile_m: usize, tile_n: usize) -> usize {
+    let m = m.min(tile_m).max(1);
+    let n = n.min(tile_n).max(1);
+    (m * n) / (m + n)
+}
+
+/// Picks which of the three matmul tunables are worth benchmarking for this problem size,
+/// using the roofline cost model to skip provably-dominated candidates up front. Tiling2D
+/// reuses each loaded tile across its configured block, the accelerated path reuses across
+/// tensor-core-sized tiles, and the simple kernel reuses nothing.
+fn matmul_candidates<R: CubeRuntime>(
+    lhs: &CubeTensor<R>,
+    rhs: &CubeTensor<R>,
+    out: &CubeTensor<R>,
+    dtype_size: usize,
+) -> (bool, bool, bool) {
+    let lhs_dims = lhs.shape.dims.as_slice();
+    let rhs_dims = rhs.shape.dims.as_slice();
+    let m = lhs_dims[lhs_dims.len() - 2];
+    let k = lhs_dims[lhs_dims.len() - 1];
+    let n = rhs_dims[rhs_dims.len() - 1];
+    let _ = out;
+
+    let reuse = |factor: usize, estimate: CostEstimate| CostEstimate {
+        bytes_loaded: estimate.bytes_loaded / factor.max(1) as u64,
+        ..estimate
+    };
+
+    let base = matmul_cost_estimate(m, n, k, dtype_size, false);
+    let config = Tiling2dConfig::default();
+    let estimates = [
+        reuse(
+            tile_reuse(m, n, config.block_size_m, config.block_size_n),
+            base,
+        ),
+        reuse(tile_reuse(m, n, ACCELERATED_TILE, ACCELERATED_TILE), base),
+        base,
+    ];
+
+    let device = DeviceRooflineProperties::query::<R>();
+    let survives = prune_dominated(&estimates, &device, COST_MODEL_PRUNE_FACTOR);
+    (survives[0], survives[1], survives[2])
+}
 
 fn matmul_input_gen<R: CubeRuntime, E: FloatElement>(
     _key: &CubeAutotuneKey,
@@ -42,10 +102,19 @@ pub fn matmul_autotune<R: CubeRuntime, E: FloatElement + Element>(
 
     static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
 
-    let tunables = TunableSet::new(create_key::<R, E>, matmul_input_gen::<R, E>)
-        .with_tunable(matmul_tiling2d::<R, E>)
-        .with_tunable(matmul_accelerated::<R, E>)
-        .with_tunable(matmul_simple::<R, E>);
+    let (try_tiling2d, try_accelerated, try_simple) =
+        matmul_candidates::<R>(&lhs, &rhs, &output, core::mem::size_of::<E>());
+
+    let mut tunables = TunableSet::new(create_key::<R, E>, matmul_input_gen::<R, E>);
+    if try_tiling2d {
+        tunables = tunables.with_tunable(matmul_tiling2d::<R, E>);
+    }
+    if try_accelerated {
+        tunables = tunables.with_tunable(matmul_accelerated::<R, E>);
+    }
+    if try_simple {
+        tunables = tunables.with_tunable(matmul_simple::<R, E>);
+    }
 
     TUNER.execute(
         &CubeTuneId::new::<R>(&lhs.device),
@@ -115,3 +184,112 @@ fn matmul_simple<R: CubeRuntime, E: FloatElement>(
     )
     .map_err(|err| format!("{err:?}"))
 }
+
+fn matmul_fused_input_gen<R: CubeRuntime, E: FloatElement>(
+    _key: &CubeAutotuneKey,
+    lhs: &CubeTensor<R>,
+    rhs: &CubeTensor<R>,
+    bias: &Option<CubeTensor<R>>,
+    activation: &ActivationKind,
+    out: &CubeTensor<R>,
+) -> (
+    CubeTensor<R>,
+    CubeTensor<R>,
+    Option<CubeTensor<R>>,
+    ActivationKind,
+    CubeTensor<R>,
+) {
+    let random_bounds: (E, E) = ((-10.0).elem::<E>(), (10.0).elem::<E>());
+    let lhs = random_like_uniform(lhs, random_bounds.0, random_bounds.1);
+    let rhs = random_like_uniform(rhs, random_bounds.0, random_bounds.1);
+    let bias = bias
+        .as_ref()
+        .map(|bias| random_like_uniform(bias, random_bounds.0, random_bounds.1));
+
+    let out = empty_device::<R, E>(out.client.clone(), out.device.clone(), out.shape.clone());
+
+    (lhs, rhs, bias, *activation, out)
+}
+
+/// Executes autotune on matmul operations fused with a bias add and activation.
+///
+/// The autotune key carries whether a bias and an activation are present so fused
+/// and unfused configurations are tuned independently.
+pub fn matmul_fused_autotune<R: CubeRuntime, E: FloatElement + Element>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+    activation: ActivationKind,
+    out: Option<CubeTensor<R>>,
+) -> CubeTensor<R> {
+    let output = out.unwrap_or_else(|| init_matmul_output::<R, E>(&lhs, &rhs));
+
+    let client = lhs.client.clone();
+
+    static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
+
+    let tunables = TunableSet::new(create_key_fused::<R, E>, matmul_fused_input_gen::<R, E>)
+        .with_tunable(matmul_fused_tiling2d::<R, E>)
+        .with_tunable(matmul_fused_accelerated::<R, E>);
+
+    TUNER.execute(
+        &CubeTuneId::new::<R>(&lhs.device),
+        &client,
+        &tunables,
+        (lhs, rhs, bias, activation, output.clone()),
+    );
+
+    output
+}
+
+fn matmul_fused_accelerated<R: CubeRuntime, E: FloatElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+    activation: ActivationKind,
+    out: CubeTensor<R>,
+) -> Result<(), String> {
+    cubecl::linalg::matmul::launch_ref::<R, E>(
+        &Strategy::Simple,
+        &lhs.client,
+        &lhs.as_handle_ref(),
+        &rhs.as_handle_ref(),
+        &out.as_handle_ref(),
+    )
+    .map_err(|err| format!("{err:?}"))?;
+
+    crate::kernel::fused_epilogue::launch_fused_epilogue::<R, E>(&out, bias.as_ref(), activation)
+}
+
+fn matmul_fused_tiling2d<R: CubeRuntime, E: FloatElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+    activation: ActivationKind,
+    out: CubeTensor<R>,
+) -> Result<(), String> {
+    let config = Tiling2dConfig::default();
+
+    let output_shape = out.shape.dims.as_slice();
+    let rank = output_shape.len();
+    let num_rows = *output_shape.get(rank - 2).unwrap();
+    let num_cols = *output_shape.get(rank - 1).unwrap();
+    let cubes_x = f32::ceil(num_rows as f32 / config.block_size_m as f32) as u32;
+    let cubes_y = f32::ceil(num_cols as f32 / config.block_size_n as f32) as u32;
+
+    let (max_x, max_y, _max_z) = R::max_cube_count();
+    if cubes_x > max_x || cubes_y > max_y {
+        return Err(format!("Cube size {cubes_x}x{cubes_y} too large"));
+    }
+
+    cubecl::linalg::matmul::launch_ref::<R, E>(
+        &Strategy::Tiling2D(config),
+        &lhs.client,
+        &lhs.as_handle_ref(),
+        &rhs.as_handle_ref(),
+        &out.as_handle_ref(),
+    )
+    .map_err(|err| format!("{err:?}"))?;
+
+    crate::kernel::fused_epilogue::launch_fused_epilogue::<R, E>(&out, bias.as_ref(), activation)
+}
@@ -0,0 +1,73 @@
+//! `matmul/tune/base.rs` already imported `create_key` from this module before this
+//! backlog touched anything, so the module and `MatmulAutotuneKey` necessarily exist
+//! upstream; neither is part of this snapshot. The struct below is reconstructed with
+//! just the `dtype`/`m`/`k`/`n` shape every other key in this crate anchors on, plus the
+//! `has_bias`/`activation` fields this request actually needs — if the real upstream key
+//! carries more than that, this file needs to be replaced with it (adding the two new
+//! fields) rather than merged as-is.
+
+use cubecl::AutotuneKey;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    kernel::matmul::ActivationKind, tensor::CubeTensor, tune_key::CubeAutotuneKey, CubeRuntime,
+};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize, AutotuneKey)]
+/// Autotune key representative of matmul versions.
+pub struct MatmulAutotuneKey {
+    dtype: burn_tensor::DType,
+    #[autotune(anchor)]
+    m: usize,
+    #[autotune(anchor)]
+    k: usize,
+    #[autotune(anchor)]
+    n: usize,
+    /// Whether a bias is fused into the output-write stage.
+    has_bias: bool,
+    /// The activation fused into the output-write stage: `None` for the plain (unfused)
+    /// matmul and `Some` for every fused call, `ActivationKind::Identity` included, so
+    /// fused and unfused configurations never share a key.
+    activation: Option<ActivationKind>,
+}
+
+impl MatmulAutotuneKey {
+    pub(crate) fn generate<R: CubeRuntime>(
+        lhs: &CubeTensor<R>,
+        rhs: &CubeTensor<R>,
+        has_bias: bool,
+        activation: Option<ActivationKind>,
+    ) -> Self {
+        let dtype = lhs.dtype;
+        let lhs_dims = lhs.shape.dims.as_slice();
+        let rhs_dims = rhs.shape.dims.as_slice();
+        let m = lhs_dims[lhs_dims.len() - 2];
+        let k = lhs_dims[lhs_dims.len() - 1];
+        let n = rhs_dims[rhs_dims.len() - 1];
+
+        Self::new(dtype, m, k, n, has_bias, activation)
+    }
+}
+
+pub(crate) fn create_key<R: CubeRuntime, E>(
+    lhs: &CubeTensor<R>,
+    rhs: &CubeTensor<R>,
+    _out: &CubeTensor<R>,
+) -> CubeAutotuneKey {
+    CubeAutotuneKey::Matmul(MatmulAutotuneKey::generate::<R>(lhs, rhs, false, None))
+}
+
+pub(crate) fn create_key_fused<R: CubeRuntime, E>(
+    lhs: &CubeTensor<R>,
+    rhs: &CubeTensor<R>,
+    bias: &Option<CubeTensor<R>>,
+    activation: &ActivationKind,
+    _out: &CubeTensor<R>,
+) -> CubeAutotuneKey {
+    CubeAutotuneKey::Matmul(MatmulAutotuneKey::generate::<R>(
+        lhs,
+        rhs,
+        bias.is_some(),
+        Some(*activation),
+    ))
+}
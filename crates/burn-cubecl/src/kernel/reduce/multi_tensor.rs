@@ -0,0 +1,121 @@
+//! Multi-tensor sum and L2-norm reduction across a list of independently-allocated
+//! tensors.
+//!
+//! CubeCL kernels are monomorphized ahead of time over a fixed, statically-typed
+//! argument list, so there's no safe way to hand an arbitrary-length, runtime-determined
+//! list of tensor handles to a single kernel launch the way a raw-pointer
+//! "multi-tensor-apply" technique would. What's implemented here instead: one launch per
+//! input tensor that reduces it (optionally squaring first, for L2 norm) directly into
+//! its own slot of one shared accumulator buffer, followed by one more launch that
+//! reduces that buffer to the final scalar. That's `N + 1` launches for `N` tensors
+//! (`N + 2` for L2 norm, plus the closing sqrt), against `2N + 1` in an earlier version of
+//! this file that reduced each tensor to its own buffer and then issued a second launch
+//! per tensor just to gather those scalars together. It still isn't the single-launch
+//! fusion the original request asked for, and the per-tensor reduction below is a serial
+//! scan on one thread (correctness and launch count over peak throughput) rather than a
+//! parallel tree reduction — call this a launch-count reduction over the naive
+//! one-call-per-tensor path, not a true multi-tensor-apply kernel.
+
+use cubecl::prelude::*;
+
+use crate::{tensor::CubeTensor, CubeElement, CubeRuntime};
+
+#[cube(launch_unchecked)]
+fn reduce_tensor_into_slot<FIn: Float, FOut: Float>(
+    input: &Tensor<FIn>,
+    output: &mut Tensor<FOut>,
+    #[comptime] slot: u32,
+    #[comptime] square: bool,
+) {
+    if ABSOLUTE_POS == 0 {
+        let mut acc = FOut::new(0.0);
+        for i in 0..input.len() {
+            let value = FOut::cast_from(input[i]);
+            let value = if comptime!(square) { value * value } else { value };
+            acc += value;
+        }
+        output[slot] = acc;
+    }
+}
+
+#[cube(launch_unchecked)]
+fn sqrt_scalar_kernel<F: Float>(value: &mut Tensor<F>) {
+    if ABSOLUTE_POS == 0 {
+        value[0] = F::sqrt(value[0]);
+    }
+}
+
+fn zeroed<Run: CubeRuntime, Acc: CubeElement>(
+    tensors: &[CubeTensor<Run>],
+    len: usize,
+) -> CubeTensor<Run> {
+    let client = tensors[0].client.clone();
+    let device = tensors[0].device.clone();
+    let zeros = vec![Acc::from_int(0); len];
+    let handle = client.create(Acc::as_bytes(&zeros));
+    CubeTensor::new_contiguous(client, device, [len].into(), handle, Acc::dtype())
+}
+
+fn reduce_list<Run: CubeRuntime, E: CubeElement, Acc: CubeElement>(
+    tensors: &[CubeTensor<Run>],
+    square: bool,
+) -> CubeTensor<Run> {
+    let combined = zeroed::<Run, Acc>(tensors, tensors.len());
+
+    for (slot, tensor) in tensors.iter().enumerate() {
+        unsafe {
+            reduce_tensor_into_slot::launch_unchecked::<E, Acc, Run>(
+                &tensor.client,
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new(1, 1, 1),
+                tensor.as_tensor_arg(1),
+                combined.as_tensor_arg(1),
+                slot as u32,
+                square,
+            );
+        }
+    }
+
+    let output = zeroed::<Run, Acc>(tensors, 1);
+    unsafe {
+        reduce_tensor_into_slot::launch_unchecked::<Acc, Acc, Run>(
+            &output.client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(1, 1, 1),
+            combined.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            0,
+            false,
+        );
+    }
+
+    output
+}
+
+/// Sums every element across all of `tensors` into a single scalar. `Acc` is the
+/// accumulation precision; pass `E` for same-precision accumulation or `f32` to
+/// accumulate low-precision (`f16`/`bf16`) inputs at higher precision.
+pub(crate) fn multi_tensor_sum<Run: CubeRuntime, E: CubeElement, Acc: CubeElement>(
+    tensors: &[CubeTensor<Run>],
+) -> Result<CubeTensor<Run>, String> {
+    Ok(reduce_list::<Run, E, Acc>(tensors, false))
+}
+
+/// Computes `sqrt(sum(t_i^2 for t_i in tensors))` across all of `tensors`, accumulating
+/// in `Acc` precision.
+pub(crate) fn multi_tensor_l2_norm<Run: CubeRuntime, E: CubeElement, Acc: CubeElement>(
+    tensors: &[CubeTensor<Run>],
+) -> Result<CubeTensor<Run>, String> {
+    let sum_of_squares = reduce_list::<Run, E, Acc>(tensors, true);
+
+    unsafe {
+        sqrt_scalar_kernel::launch_unchecked::<Acc, Run>(
+            &sum_of_squares.client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(1, 1, 1),
+            sum_of_squares.as_tensor_arg(1),
+        );
+    }
+
+    Ok(sum_of_squares)
+}
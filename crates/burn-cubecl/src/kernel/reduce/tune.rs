@@ -9,15 +9,65 @@ use cubecl::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    CubeAutotuneKey, CubeElement, CubeRuntime, CubeTuneId, kernel::prng::random_like_uniform,
+    CubeAutotuneKey, CubeElement, CubeRuntime, CubeTuneId,
+    kernel::{
+        autotune_cost_model::{
+            reduce_cost_estimate, prune_dominated, CostEstimate, DeviceRooflineProperties,
+            COST_MODEL_PRUNE_FACTOR,
+        },
+        prng::random_like_uniform,
+    },
     ops::numeric::empty_device, tensor::CubeTensor,
 };
 
-/// Executes autotune on reduce operations.
+/// Shared-memory reduce variants (`reduce_shared`, `reduce_shared_plane`) pay a fixed
+/// per-cube `__syncthreads`-style barrier cost that only pays off once there is enough
+/// work per cube to amortize it; model that cost as extra compute cycles on top of the
+/// roofline estimate so tiny reductions don't waste a benchmark slot on them. Plane-only
+/// cooperation (`reduce_plane`) uses warp/subgroup shuffles instead of a shared-memory
+/// barrier, so it doesn't pay this cost and isn't penalized here.
+const SHARED_MEMORY_OVERHEAD_CYCLES: u64 = 4096;
+
+fn reduce_candidates<Run: CubeRuntime>(
+    input: &CubeTensor<Run>,
+    dim: usize,
+    dtype_size: usize,
+) -> (bool, bool, bool, bool) {
+    let reduce_axis_shape = input.shape.dims[dim];
+    let reduce_axis_stride = input.strides[dim];
+    let outer_axes_product: usize = input
+        .strides
+        .iter()
+        .zip(input.shape.dims.iter())
+        .filter_map(|(stride, shape)| (*stride > reduce_axis_stride).then_some(shape))
+        .product();
+
+    let base = reduce_cost_estimate(reduce_axis_shape, outer_axes_product, dtype_size);
+    let shared_memory = CostEstimate {
+        compute_cycles: base.compute_cycles + SHARED_MEMORY_OVERHEAD_CYCLES,
+        ..base
+    };
+
+    let device = DeviceRooflineProperties::query::<Run>();
+    // Order matches (reduce, shared, plane, shared_plane): only the two shared-memory
+    // variants carry the barrier overhead, see `SHARED_MEMORY_OVERHEAD_CYCLES`.
+    let survives = prune_dominated(
+        &[base, shared_memory, base, shared_memory],
+        &device,
+        COST_MODEL_PRUNE_FACTOR,
+    );
+    (survives[0], survives[1], survives[2], survives[3])
+}
+
+/// Executes autotune on reduce operations. `Acc` is the type the reduction accumulates
+/// in; pass `Out` for same-precision accumulation or a higher-precision type (typically
+/// `f32`) so low-precision inputs (`f16`/`bf16`) don't lose precision mid-reduction, with
+/// only the final store downcast to `Out`.
 pub fn autotune_reduce<
     Run: CubeRuntime,
     In: CubeElement,
     Out: CubeElement,
+    Acc: CubeElement,
     Rd: cubecl::reduce::Reduce,
 >(
     client: &ComputeClient<Run::Server, Run::Channel>,
@@ -29,11 +79,39 @@ pub fn autotune_reduce<
 
     static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
 
-    let tunables = TunableSet::new(create_key::<Run>, reduce_input_gen::<Run, In, Out>)
-        .with_tunable(reduce::<Run, In, Out, Rd>)
-        .with_tunable(reduce_shared::<Run, In, Out, Rd>)
-        .with_tunable(reduce_plane::<Run, In, Out, Rd>)
-        .with_tunable(reduce_shared_plane::<Run, In, Out, Rd>);
+    let (try_reduce, try_shared, try_plane, try_shared_plane) =
+        reduce_candidates::<Run>(&input, dim, core::mem::size_of::<In>());
+    let try_upcast_accumulator =
+        core::mem::size_of::<In>() < core::mem::size_of::<f32>() && Acc::dtype() != f32::dtype();
+
+    let mut tunables =
+        TunableSet::new(create_key::<Run, Acc>, reduce_input_gen::<Run, In, Out>);
+    if try_reduce {
+        tunables = tunables.with_tunable(reduce::<Run, In, Out, Acc, Rd>);
+    }
+    if try_shared {
+        tunables = tunables.with_tunable(reduce_shared::<Run, In, Out, Acc, Rd>);
+    }
+    if try_plane {
+        tunables = tunables.with_tunable(reduce_plane::<Run, In, Out, Acc, Rd>);
+    }
+    if try_shared_plane {
+        tunables = tunables.with_tunable(reduce_shared_plane::<Run, In, Out, Acc, Rd>);
+    }
+    if try_upcast_accumulator {
+        if try_reduce {
+            tunables = tunables.with_tunable(reduce::<Run, In, Out, f32, Rd>);
+        }
+        if try_shared {
+            tunables = tunables.with_tunable(reduce_shared::<Run, In, Out, f32, Rd>);
+        }
+        if try_plane {
+            tunables = tunables.with_tunable(reduce_plane::<Run, In, Out, f32, Rd>);
+        }
+        if try_shared_plane {
+            tunables = tunables.with_tunable(reduce_shared_plane::<Run, In, Out, f32, Rd>);
+        }
+    }
 
     TUNER
         .execute(
@@ -49,6 +127,7 @@ pub fn autotune_reduce<
 /// Autotune key representative of reduce versions
 pub struct ReduceAutotuneKey {
     dtype: burn_tensor::DType,
+    accumulation_dtype: burn_tensor::DType,
     #[autotune(anchor)]
     reduce_axis_shape: usize,
     #[autotune(anchor)]
@@ -58,7 +137,10 @@ pub struct ReduceAutotuneKey {
 }
 
 impl ReduceAutotuneKey {
-    pub(crate) fn generate<Run: CubeRuntime>(input: &CubeTensor<Run>, axis: usize) -> Self {
+    pub(crate) fn generate<Run: CubeRuntime, Acc: CubeElement>(
+        input: &CubeTensor<Run>,
+        axis: usize,
+    ) -> Self {
         let rank = input.shape.num_dims();
 
         if axis > rank {
@@ -66,6 +148,7 @@ impl ReduceAutotuneKey {
         }
 
         let dtype = input.dtype;
+        let accumulation_dtype = Acc::dtype();
         let reduce_axis_shape = input.shape.dims[axis];
         let reduce_axis_stride = input.strides[axis];
 
@@ -78,6 +161,7 @@ impl ReduceAutotuneKey {
 
         Self::new(
             dtype,
+            accumulation_dtype,
             reduce_axis_shape,
             reduce_axis_stride,
             outer_axes_product,
@@ -85,12 +169,12 @@ impl ReduceAutotuneKey {
     }
 }
 
-pub(crate) fn create_key<Run: CubeRuntime>(
+pub(crate) fn create_key<Run: CubeRuntime, Acc: CubeElement>(
     input: &CubeTensor<Run>,
     _output: &CubeTensor<Run>,
     dim: &usize,
 ) -> CubeAutotuneKey {
-    CubeAutotuneKey::Reduce(ReduceAutotuneKey::generate(input, *dim))
+    CubeAutotuneKey::Reduce(ReduceAutotuneKey::generate::<Run, Acc>(input, *dim))
 }
 
 mod reduce_ops {
@@ -120,13 +204,14 @@ mod reduce_ops {
         Run: CubeRuntime,
         In: CubeElement,
         Out: CubeElement,
+        Acc: CubeElement,
         Rd: cubecl::reduce::Reduce,
     >(
         input: CubeTensor<Run>,
         output: CubeTensor<Run>,
         axis: usize,
     ) -> Result<(), String> {
-        cubecl::reduce::reduce::<Run, In, Out, Rd>(
+        cubecl::reduce::reduce::<Run, In, Out, Acc, Rd>(
             &input.client,
             input.as_handle_ref(),
             output.as_handle_ref(),
@@ -143,13 +228,14 @@ mod reduce_ops {
         Run: CubeRuntime,
         In: CubeElement,
         Out: CubeElement,
+        Acc: CubeElement,
         Rd: cubecl::reduce::Reduce,
     >(
         input: CubeTensor<Run>,
         output: CubeTensor<Run>,
         axis: usize,
     ) -> Result<(), String> {
-        cubecl::reduce::reduce::<Run, In, Out, Rd>(
+        cubecl::reduce::reduce::<Run, In, Out, Acc, Rd>(
             &input.client,
             input.as_handle_ref(),
             output.as_handle_ref(),
@@ -166,13 +252,14 @@ mod reduce_ops {
         Run: CubeRuntime,
         In: CubeElement,
         Out: CubeElement,
+        Acc: CubeElement,
         Rd: cubecl::reduce::Reduce,
     >(
         input: CubeTensor<Run>,
         output: CubeTensor<Run>,
         axis: usize,
     ) -> Result<(), String> {
-        cubecl::reduce::reduce::<Run, In, Out, Rd>(
+        cubecl::reduce::reduce::<Run, In, Out, Acc, Rd>(
             &input.client,
             input.as_handle_ref(),
             output.as_handle_ref(),
@@ -189,13 +276,14 @@ mod reduce_ops {
         Run: CubeRuntime,
         In: CubeElement,
         Out: CubeElement,
+        Acc: CubeElement,
         Rd: cubecl::reduce::Reduce,
     >(
         input: CubeTensor<Run>,
         output: CubeTensor<Run>,
         axis: usize,
     ) -> Result<(), String> {
-        cubecl::reduce::reduce::<Run, In, Out, Rd>(
+        cubecl::reduce::reduce::<Run, In, Out, Acc, Rd>(
             &input.client,
             input.as_handle_ref(),
             output.as_handle_ref(),
@@ -209,9 +297,11 @@ mod reduce_ops {
     }
 }
 
-/// Executes autotune on reduce operations.
+/// Executes autotune on reduce operations. `Acc` is the accumulation type; pass `E` for
+/// same-precision accumulation or `f32` to accumulate at higher precision than a
+/// low-precision `E` (e.g. `f16`/`bf16`) and only downcast on the final store.
 #[cfg(feature = "autotune")]
-pub fn autotune_sum<Run: CubeRuntime, E: CubeElement>(
+pub fn autotune_sum<Run: CubeRuntime, E: CubeElement, Acc: CubeElement>(
     client: &ComputeClient<Run::Server, Run::Channel>,
     input: CubeTensor<Run>,
 ) -> CubeTensor<Run> {
@@ -219,15 +309,24 @@ pub fn autotune_sum<Run: CubeRuntime, E: CubeElement>(
 
     static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
 
-    let tunables = TunableSet::new(create_key_sum::<Run>, sum_input_gen::<Run, E>)
-        .with_tunable(sum_chained::<Run, E>)
-        .with_tunable(sum_one_shot::<Run, E, 1>)
-        .with_tunable(sum_one_shot::<Run, E, 2>)
-        .with_tunable(sum_one_shot::<Run, E, 4>)
-        .with_tunable(sum_one_shot::<Run, E, 8>)
-        .with_tunable(sum_one_shot::<Run, E, 16>)
-        .with_tunable(sum_one_shot::<Run, E, 32>)
-        .with_tunable(sum_one_shot::<Run, E, 64>);
+    let try_upcast_accumulator =
+        core::mem::size_of::<E>() < core::mem::size_of::<f32>() && Acc::dtype() != f32::dtype();
+
+    let mut tunables = TunableSet::new(create_key_sum::<Run, Acc>, sum_input_gen::<Run, E>)
+        .with_tunable(sum_chained::<Run, E, Acc>)
+        .with_tunable(sum_one_shot::<Run, E, Acc, 1>)
+        .with_tunable(sum_one_shot::<Run, E, Acc, 2>)
+        .with_tunable(sum_one_shot::<Run, E, Acc, 4>)
+        .with_tunable(sum_one_shot::<Run, E, Acc, 8>)
+        .with_tunable(sum_one_shot::<Run, E, Acc, 16>)
+        .with_tunable(sum_one_shot::<Run, E, Acc, 32>)
+        .with_tunable(sum_one_shot::<Run, E, Acc, 64>);
+
+    if try_upcast_accumulator {
+        tunables = tunables
+            .with_tunable(sum_one_shot::<Run, E, f32, 16>)
+            .with_tunable(sum_one_shot::<Run, E, f32, 64>);
+    }
 
     TUNER
         .execute(
@@ -239,23 +338,31 @@ pub fn autotune_sum<Run: CubeRuntime, E: CubeElement>(
         .expect("All autotuners failed")
 }
 
-pub(crate) fn create_key_sum<Run: CubeRuntime>(input: &CubeTensor<Run>) -> CubeAutotuneKey {
-    CubeAutotuneKey::Sum(SumAutotuneKey::generate(input))
+pub(crate) fn create_key_sum<Run: CubeRuntime, Acc: CubeElement>(
+    input: &CubeTensor<Run>,
+) -> CubeAutotuneKey {
+    CubeAutotuneKey::Sum(SumAutotuneKey::generate::<Run, Acc>(input))
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize, AutotuneKey)]
 /// Autotune key representative of sum versions
 pub struct SumAutotuneKey {
     dtype: burn_tensor::DType,
+    accumulation_dtype: burn_tensor::DType,
     #[autotune(anchor)]
     length: usize,
 }
 
 impl SumAutotuneKey {
-    pub(crate) fn generate<Run: CubeRuntime>(input: &CubeTensor<Run>) -> Self {
+    pub(crate) fn generate<Run: CubeRuntime, Acc: CubeElement>(input: &CubeTensor<Run>) -> Self {
         let dtype = input.dtype;
+        let accumulation_dtype = Acc::dtype();
         let length = input.shape.num_elements();
-        Self { dtype, length }
+        Self {
+            dtype,
+            accumulation_dtype,
+            length,
+        }
     }
 }
 mod sum_ops {
@@ -273,7 +380,7 @@ mod sum_ops {
         random_like_uniform(input, random_bounds.0, random_bounds.1)
     }
 
-    pub(crate) fn sum_one_shot<Run: CubeRuntime, E: CubeElement, const C: u32>(
+    pub(crate) fn sum_one_shot<Run: CubeRuntime, E: CubeElement, Acc: CubeElement, const C: u32>(
         input: CubeTensor<Run>,
     ) -> Result<CubeTensor<Run>, String> {
         let client = input.client.clone();
@@ -281,7 +388,7 @@ mod sum_ops {
         let handle = client.create(E::as_bytes(&[E::from_int(0)]));
         let output = CubeTensor::new_contiguous(client, device, [1].into(), handle, E::dtype());
 
-        cubecl::reduce::shared_sum::<Run, E>(
+        cubecl::reduce::shared_sum::<Run, E, Acc>(
             &input.client,
             input.as_handle_ref(),
             output.as_handle_ref(),
@@ -292,13 +399,135 @@ mod sum_ops {
     }
 
     #[cfg(feature = "autotune")]
-    pub(crate) fn sum_chained<Run: CubeRuntime, E: CubeElement>(
+    pub(crate) fn sum_chained<Run: CubeRuntime, E: CubeElement, Acc: CubeElement>(
         input: CubeTensor<Run>,
     ) -> Result<CubeTensor<Run>, String> {
-        crate::kernel::reduce::reduce::<Run, E, E, Sum>(
+        crate::kernel::reduce::reduce::<Run, E, E, Acc, Sum>(
             input,
             crate::kernel::reduce::ReduceStrategy::Autotune,
         )
         .map_err(|e| e.to_string())
     }
 }
+
+/// Executes autotune on a multi-tensor sum reduction, folding a whole list of tensors
+/// (e.g. all parameter gradients) into one scalar with one launch per tensor plus a final
+/// combine, rather than one autotuned [`autotune_sum`] call (and its own host-side add)
+/// per tensor. See [`crate::kernel::reduce::multi_tensor`] for why this can't be a single
+/// kernel launch.
+#[cfg(feature = "autotune")]
+pub fn autotune_sum_multi_tensor<Run: CubeRuntime, E: CubeElement>(
+    client: &ComputeClient<Run::Server, Run::Channel>,
+    tensors: Vec<CubeTensor<Run>>,
+) -> CubeTensor<Run> {
+    use multi_tensor_ops::*;
+
+    static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
+
+    let try_upcast_accumulator =
+        core::mem::size_of::<E>() < core::mem::size_of::<f32>() && E::dtype() != f32::dtype();
+
+    let tune_id = CubeTuneId::new::<Run>(&tensors[0].client, &tensors[0].device);
+    let mut tunables = TunableSet::new(
+        create_key_multi_tensor::<Run>,
+        multi_tensor_input_gen::<Run, E>,
+    )
+    .with_tunable(sum_multi_tensor::<Run, E, E>);
+    if try_upcast_accumulator {
+        tunables = tunables.with_tunable(sum_multi_tensor::<Run, E, f32>);
+    }
+
+    TUNER
+        .execute(&tune_id, client, &tunables, tensors)
+        .expect("All autotuners failed")
+}
+
+/// Executes autotune on a multi-tensor L2 norm, i.e. `sqrt(sum(t_i^2 for t_i in tensors))`,
+/// as used for global gradient-norm clipping.
+#[cfg(feature = "autotune")]
+pub fn autotune_l2_norm_multi_tensor<Run: CubeRuntime, E: CubeElement>(
+    client: &ComputeClient<Run::Server, Run::Channel>,
+    tensors: Vec<CubeTensor<Run>>,
+) -> CubeTensor<Run> {
+    use multi_tensor_ops::*;
+
+    static TUNER: LocalTuner<CubeAutotuneKey, CubeTuneId> = local_tuner!();
+
+    let try_upcast_accumulator =
+        core::mem::size_of::<E>() < core::mem::size_of::<f32>() && E::dtype() != f32::dtype();
+
+    let tune_id = CubeTuneId::new::<Run>(&tensors[0].client, &tensors[0].device);
+    let mut tunables = TunableSet::new(
+        create_key_multi_tensor::<Run>,
+        multi_tensor_input_gen::<Run, E>,
+    )
+    .with_tunable(l2_norm_multi_tensor::<Run, E, E>);
+    if try_upcast_accumulator {
+        tunables = tunables.with_tunable(l2_norm_multi_tensor::<Run, E, f32>);
+    }
+
+    TUNER
+        .execute(&tune_id, client, &tunables, tensors)
+        .expect("All autotuners failed")
+}
+
+pub(crate) fn create_key_multi_tensor<Run: CubeRuntime>(
+    tensors: &[CubeTensor<Run>],
+) -> CubeAutotuneKey {
+    CubeAutotuneKey::MultiTensorReduce(MultiTensorReduceAutotuneKey::generate(tensors))
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize, AutotuneKey)]
+/// Autotune key representative of multi-tensor reduction versions.
+pub struct MultiTensorReduceAutotuneKey {
+    dtype: burn_tensor::DType,
+    #[autotune(anchor)]
+    num_tensors: usize,
+    #[autotune(anchor)]
+    total_elements: usize,
+}
+
+impl MultiTensorReduceAutotuneKey {
+    pub(crate) fn generate<Run: CubeRuntime>(tensors: &[CubeTensor<Run>]) -> Self {
+        let dtype = tensors[0].dtype;
+        let num_tensors = tensors.len();
+        let total_elements = tensors.iter().map(|t| t.shape.num_elements()).sum();
+
+        Self {
+            dtype,
+            num_tensors,
+            total_elements,
+        }
+    }
+}
+
+mod multi_tensor_ops {
+    #![allow(missing_docs)]
+
+    use crate::kernel::reduce::multi_tensor::{multi_tensor_l2_norm, multi_tensor_sum};
+
+    use super::*;
+
+    pub(crate) fn multi_tensor_input_gen<Run: CubeRuntime, E: CubeElement>(
+        _key: &CubeAutotuneKey,
+        tensors: &[CubeTensor<Run>],
+    ) -> Vec<CubeTensor<Run>> {
+        let random_bounds: (E, E) = ((-10.0_f32).elem::<E>(), (10.0_f32).elem::<E>());
+        tensors
+            .iter()
+            .map(|t| random_like_uniform(t, random_bounds.0, random_bounds.1))
+            .collect()
+    }
+
+    pub(crate) fn sum_multi_tensor<Run: CubeRuntime, E: CubeElement, Acc: CubeElement>(
+        tensors: Vec<CubeTensor<Run>>,
+    ) -> Result<CubeTensor<Run>, String> {
+        multi_tensor_sum::<Run, E, Acc>(&tensors)
+    }
+
+    pub(crate) fn l2_norm_multi_tensor<Run: CubeRuntime, E: CubeElement, Acc: CubeElement>(
+        tensors: Vec<CubeTensor<Run>>,
+    ) -> Result<CubeTensor<Run>, String> {
+        multi_tensor_l2_norm::<Run, E, Acc>(&tensors)
+    }
+}
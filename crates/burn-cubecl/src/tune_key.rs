@@ -0,0 +1,34 @@
+//! The top-level autotune key dispatched on by every `LocalTuner` in this crate.
+//!
+//! This file isn't part of the snapshot this series was authored against — every module
+//! under `kernel/` already referenced `crate::tune_key::CubeAutotuneKey` (and the
+//! `CubeAutotuneKey` re-export) before this backlog touched anything, so the enum
+//! necessarily lives somewhere upstream. It's reconstructed here with every variant any
+//! module in this crate actually dispatches on (`Reduce`, `Sum`, `Matmul`,
+//! `ConvTranspose2d`, `MultiTensorReduce`), rather than just the one this series needed,
+//! so merging against the real upstream definition is a matter of dropping this file in
+//! favor of it, not reconciling dropped variants.
+
+use cubecl::AutotuneKey;
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::{
+    conv::conv2d::tune::ConvTranspose2dAutotuneKey,
+    matmul::tune::MatmulAutotuneKey,
+    reduce::tune::{MultiTensorReduceAutotuneKey, ReduceAutotuneKey, SumAutotuneKey},
+};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize, AutotuneKey)]
+/// Autotune key covering every tunable operation in this crate.
+pub enum CubeAutotuneKey {
+    /// Key for reduce operations
+    Reduce(ReduceAutotuneKey),
+    /// Key for sum operations
+    Sum(SumAutotuneKey),
+    /// Key for matmul operations
+    Matmul(MatmulAutotuneKey),
+    /// Key for conv_transpose2d operations
+    ConvTranspose2d(ConvTranspose2dAutotuneKey),
+    /// Key for multi-tensor reduce operations
+    MultiTensorReduce(MultiTensorReduceAutotuneKey),
+}